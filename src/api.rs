@@ -0,0 +1,40 @@
+// Uses
+use crate::{
+	error::{SponsorBlockError, SponsorBlockResult},
+	segment::{ActionType, ActionableSegmentKind},
+};
+
+// Function Implementation
+/// Converts the API's `category` string into an [`ActionableSegmentKind`].
+///
+/// # Errors
+/// Returns [`SponsorBlockError::UnknownVariant`] if the string doesn't match
+/// a category this crate knows about.
+pub fn api_convert_segment_kind(category: &str) -> SponsorBlockResult<ActionableSegmentKind> {
+	Ok(match category {
+		"sponsor" => ActionableSegmentKind::Sponsor,
+		"selfpromo" => ActionableSegmentKind::UnpaidSelfPromotion,
+		"interaction" => ActionableSegmentKind::InteractionReminder,
+		"poi_highlight" => ActionableSegmentKind::Highlight,
+		"intro" => ActionableSegmentKind::IntermissionIntroAnimation,
+		"outro" => ActionableSegmentKind::EndcardsCredits,
+		"preview" => ActionableSegmentKind::PreviewRecap,
+		"music_offtopic" => ActionableSegmentKind::NonMusic,
+		other => return Err(SponsorBlockError::UnknownVariant(other.to_owned())),
+	})
+}
+
+/// Converts the API's `actionType` string into an [`ActionType`].
+///
+/// # Errors
+/// Returns [`SponsorBlockError::UnknownVariant`] if the string doesn't match
+/// an action type this crate knows about.
+pub fn api_convert_action_type(action_type: &str) -> SponsorBlockResult<ActionType> {
+	Ok(match action_type {
+		"skip" => ActionType::Skip,
+		"mute" => ActionType::Mute,
+		"full" => ActionType::Full,
+		"poi" => ActionType::Poi,
+		other => return Err(SponsorBlockError::UnknownVariant(other.to_owned())),
+	})
+}
@@ -0,0 +1,225 @@
+// Uses
+use std::{
+	collections::HashMap,
+	fs,
+	path::PathBuf,
+	time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::segment::AcceptedCategories;
+
+// Constants
+const DEFAULT_CAPACITY: usize = 256;
+const DEFAULT_TTL: Duration = Duration::from_secs(5 * 60);
+
+// Type Definitions
+/// Configuration for the opt-in hash-prefix response cache. See
+/// [`Client::enable_cache`](crate::Client::enable_cache).
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+	/// The maximum number of `(mirror, hash prefix, category set)`
+	/// responses to keep at once. Oldest entries are evicted first once
+	/// this is exceeded.
+	pub capacity: usize,
+	/// How long a cached response remains valid before it's treated as a
+	/// miss and re-fetched.
+	pub ttl: Duration,
+	/// An optional on-disk file the cache is loaded from on
+	/// [`Client::enable_cache`](crate::Client::enable_cache) and persisted
+	/// to on every write, so it survives process restarts.
+	///
+	/// Each write is persisted on a Tokio blocking task when called from
+	/// within one -- which [`Client::fetch_segments`](crate::Client::fetch_segments)
+	/// always is, since it's already a requirement for this crate's
+	/// reqwest-based HTTP client -- and falls back to writing inline
+	/// otherwise, which matters for
+	/// [`Client::clear_cache`](crate::Client::clear_cache), a plain sync function callers may reach for
+	/// from outside any runtime (e.g. on shutdown).
+	pub file_path: Option<PathBuf>,
+}
+
+impl Default for CacheConfig {
+	fn default() -> Self {
+		Self {
+			capacity: DEFAULT_CAPACITY,
+			ttl: DEFAULT_TTL,
+			file_path: None,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CacheKey {
+	pub(crate) base_url: String,
+	pub(crate) hash_prefix: String,
+	pub(crate) categories: AcceptedCategories,
+}
+
+struct CacheEntry {
+	raw_response: String,
+	inserted_at: Instant,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PersistedEntry {
+	base_url: String,
+	hash_prefix: String,
+	categories: u16,
+	raw_response: String,
+	inserted_at_unix_secs: u64,
+}
+
+/// An in-memory cache of whole `/skipSegments/<hashPrefix>` responses, keyed
+/// by the mirror, hash prefix, and category set they were fetched for.
+///
+/// The mirror is part of the key, not just the prefix and categories,
+/// because different mirrors in [`Client`](crate::Client)'s fallback chain
+/// can hold different data for the same video: caching across mirrors
+/// would let a response cached from one mirror silently stand in for
+/// another mirror that was never actually queried, defeating the point of
+/// falling back to it.
+///
+/// Because a hash-prefix query returns segments for every video sharing
+/// that prefix, one cached response can answer lookups for many different
+/// video IDs without another round-trip.
+pub(crate) struct SegmentCache {
+	config: CacheConfig,
+	entries: HashMap<CacheKey, CacheEntry>,
+	insertion_order: Vec<CacheKey>,
+}
+
+// Function Implementation
+impl SegmentCache {
+	pub(crate) fn new(config: CacheConfig) -> Self {
+		let mut cache = Self {
+			config,
+			entries: HashMap::new(),
+			insertion_order: Vec::new(),
+		};
+		cache.load_from_file();
+		cache
+	}
+
+	/// Returns the cached response body for `key`, if present and not
+	/// expired.
+	pub(crate) fn get(&self, key: &CacheKey) -> Option<&str> {
+		self.entries
+			.get(key)
+			.filter(|entry| entry.inserted_at.elapsed() < self.config.ttl)
+			.map(|entry| entry.raw_response.as_str())
+	}
+
+	/// Stores a response body for `key`, evicting the oldest entry first if
+	/// the cache is at capacity.
+	pub(crate) fn insert(&mut self, key: CacheKey, raw_response: String) {
+		if !self.entries.contains_key(&key) {
+			self.insertion_order.push(key.clone());
+			if self.insertion_order.len() > self.config.capacity.max(1) {
+				let oldest = self.insertion_order.remove(0);
+				self.entries.remove(&oldest);
+			}
+		}
+		self.entries.insert(
+			key,
+			CacheEntry {
+				raw_response,
+				inserted_at: Instant::now(),
+			},
+		);
+		self.save_to_file();
+	}
+
+	pub(crate) fn clear(&mut self) {
+		self.entries.clear();
+		self.insertion_order.clear();
+		self.save_to_file();
+	}
+
+	fn load_from_file(&mut self) {
+		let Some(path) = &self.config.file_path else {
+			return;
+		};
+		let Ok(contents) = fs::read_to_string(path) else {
+			return;
+		};
+		let Ok(persisted_entries) = serde_json::from_str::<Vec<PersistedEntry>>(&contents) else {
+			return;
+		};
+
+		let now = Instant::now();
+		let now_unix_secs = unix_now_secs();
+		for persisted_entry in persisted_entries {
+			let age = Duration::from_secs(now_unix_secs.saturating_sub(persisted_entry.inserted_at_unix_secs));
+			if age >= self.config.ttl {
+				continue;
+			}
+			let key = CacheKey {
+				base_url: persisted_entry.base_url,
+				hash_prefix: persisted_entry.hash_prefix,
+				categories: AcceptedCategories::from_bits(persisted_entry.categories),
+			};
+			self.insertion_order.push(key.clone());
+			self.entries.insert(
+				key,
+				CacheEntry {
+					raw_response: persisted_entry.raw_response,
+					inserted_at: now - age,
+				},
+			);
+		}
+	}
+
+	/// Persists the cache to `file_path`, if set.
+	///
+	/// Serialization and the write itself happen on a Tokio blocking task
+	/// when one is available, since this is called from
+	/// [`insert`](Self::insert), which runs on the async call path of
+	/// [`fetch_segments`](crate::Client::fetch_segments): a blocking
+	/// `fs::write` there would stall the executor thread, which matters
+	/// under [`fetch_segments_bulk`](crate::Client::fetch_segments_bulk)'s
+	/// concurrent load. It is also called from [`clear`](Self::clear), which
+	/// is reached from [`Client::clear_cache`](crate::Client::clear_cache), a plain sync
+	/// function with no runtime requirement of its own, so this falls back
+	/// to writing inline when called outside a Tokio runtime instead of
+	/// panicking. The snapshot is taken eagerly so the write reflects the
+	/// state at the time of the call even though it may land later.
+	fn save_to_file(&self) {
+		let Some(path) = self.config.file_path.clone() else {
+			return;
+		};
+		let now_unix_secs = unix_now_secs();
+		let persisted_entries: Vec<PersistedEntry> = self
+			.insertion_order
+			.iter()
+			.filter_map(|key| {
+				self.entries.get(key).map(|entry| PersistedEntry {
+					base_url: key.base_url.clone(),
+					hash_prefix: key.hash_prefix.clone(),
+					categories: key.categories.to_bits(),
+					raw_response: entry.raw_response.clone(),
+					inserted_at_unix_secs: now_unix_secs
+						.saturating_sub(entry.inserted_at.elapsed().as_secs()),
+				})
+			})
+			.collect();
+		let write = move || {
+			if let Ok(json) = serde_json::to_string(&persisted_entries) {
+				let _ = fs::write(path, json);
+			}
+		};
+		match tokio::runtime::Handle::try_current() {
+			Ok(_) => {
+				tokio::task::spawn_blocking(write);
+			}
+			Err(_) => write(),
+		}
+	}
+}
+
+fn unix_now_secs() -> u64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map_or(0, |duration| duration.as_secs())
+}
@@ -0,0 +1,61 @@
+// Uses
+use crate::{
+	error::{SponsorBlockError, SponsorBlockResult},
+	util::get_response_text,
+};
+
+// Constants
+const WATCH_URL: &str = "https://www.youtube.com/watch";
+const LENGTH_SECONDS_MARKER: &str = "\"lengthSeconds\":\"";
+
+// Type Definitions
+/// How [`Client::fetch_segments`](crate::Client::fetch_segments) should
+/// react to a segment whose end time exceeds the video's live duration by
+/// more than the configured tolerance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationValidationMode {
+	/// Keep the segment, but set [`Segment::duration_mismatch`](crate::segment::Segment::duration_mismatch).
+	Flag,
+	/// Silently drop the segment.
+	Drop,
+}
+
+// Function Implementation
+/// Scrapes a video's current duration from its YouTube watch page, the same
+/// way tools like `youtube-metadata-rs` do: by pulling `lengthSeconds` out
+/// of the page's embedded player response, without going through the
+/// (quota-limited) Data API.
+///
+/// # Errors
+/// Returns [`SponsorBlockError::BadData`] if the page doesn't contain a
+/// `lengthSeconds` field in the expected shape, or any error type that
+/// [`get_response_text`] can produce.
+pub(crate) async fn fetch_live_duration(
+	http: &reqwest::Client,
+	video_id: &str,
+) -> SponsorBlockResult<f32> {
+	let watch_page = get_response_text(
+		http.get(WATCH_URL)
+			.query(&[("v", video_id)])
+			.send()
+			.await?,
+	)
+	.await?;
+
+	let value_start = watch_page
+		.find(LENGTH_SECONDS_MARKER)
+		.map(|index| index + LENGTH_SECONDS_MARKER.len())
+		.ok_or_else(|| {
+			SponsorBlockError::BadData("watch page had no lengthSeconds field".to_owned())
+		})?;
+	let value_end = watch_page[value_start..]
+		.find('"')
+		.map(|index| value_start + index)
+		.ok_or_else(|| {
+			SponsorBlockError::BadData("lengthSeconds field was not closed".to_owned())
+		})?;
+
+	watch_page[value_start..value_end].parse::<f32>().map_err(|_| {
+		SponsorBlockError::BadData("lengthSeconds field was not a number".to_owned())
+	})
+}
@@ -0,0 +1,143 @@
+// Uses
+#[cfg(feature = "private_searches")]
+mod cache;
+#[cfg(feature = "youtube_duration_validation")]
+mod duration;
+mod user;
+
+#[cfg(feature = "private_searches")]
+pub use cache::CacheConfig;
+#[cfg(feature = "private_searches")]
+use cache::SegmentCache;
+#[cfg(feature = "youtube_duration_validation")]
+pub use duration::DurationValidationMode;
+#[cfg(feature = "private_searches")]
+use std::sync::Mutex;
+
+// Constants
+/// The default number of `/skipSegments` requests [`Client::fetch_segments_bulk`]
+/// will have in flight at once.
+const DEFAULT_BULK_CONCURRENCY: usize = 8;
+
+// Type Definitions
+/// A SponsorBlock API client.
+///
+/// Holds the HTTP client, the service identifier (e.g. `"YouTube"`), and an
+/// ordered chain of base URLs to query, where the first is tried before
+/// falling back to the next.
+pub struct Client {
+	pub(crate) http: reqwest::Client,
+	pub(crate) base_urls: Vec<String>,
+	pub(crate) service: String,
+	pub(crate) bulk_concurrency: usize,
+	#[cfg(feature = "private_searches")]
+	pub(crate) hash_prefix_length: u8,
+	#[cfg(feature = "private_searches")]
+	pub(crate) cache: Mutex<Option<SegmentCache>>,
+	#[cfg(feature = "youtube_duration_validation")]
+	pub(crate) duration_validation: Option<(DurationValidationMode, f32)>,
+}
+
+// Function Implementation
+impl Client {
+	/// Creates a client that queries a single SponsorBlock server.
+	#[must_use]
+	pub fn new(base_url: impl Into<String>, service: impl Into<String>) -> Self {
+		Self::with_base_urls(vec![base_url.into()], service)
+	}
+
+	/// Creates a client that queries an ordered chain of SponsorBlock
+	/// servers, falling back to each subsequent URL in turn.
+	///
+	/// # Panics
+	/// Panics if `base_urls` is empty.
+	#[must_use]
+	pub fn with_base_urls(base_urls: Vec<String>, service: impl Into<String>) -> Self {
+		assert!(
+			!base_urls.is_empty(),
+			"Client requires at least one base URL"
+		);
+		Self {
+			http: build_http_client(),
+			base_urls,
+			service: service.into(),
+			bulk_concurrency: DEFAULT_BULK_CONCURRENCY,
+			#[cfg(feature = "private_searches")]
+			hash_prefix_length: 4,
+			#[cfg(feature = "private_searches")]
+			cache: Mutex::new(None),
+			#[cfg(feature = "youtube_duration_validation")]
+			duration_validation: None,
+		}
+	}
+
+	/// Appends another base URL to the end of the fallback chain.
+	pub fn add_mirror(&mut self, base_url: impl Into<String>) -> &mut Self {
+		self.base_urls.push(base_url.into());
+		self
+	}
+
+	/// Sets how many `/skipSegments` requests [`Client::fetch_segments_bulk`]
+	/// is allowed to have in flight at once. Defaults to 8.
+	pub fn set_bulk_concurrency(&mut self, limit: usize) -> &mut Self {
+		self.bulk_concurrency = limit;
+		self
+	}
+
+	/// Enables the hash-prefix response cache with the given configuration.
+	///
+	/// The cache is opt-in and off by default: until this is called,
+	/// [`Client::fetch_segments`] always queries the server. This takes
+	/// `&self` rather than `&mut self` because the cache is also read and
+	/// written from behind a shared reference while fetching segments.
+	#[cfg(feature = "private_searches")]
+	pub fn enable_cache(&self, config: CacheConfig) {
+		*self.cache.lock().expect("cache mutex poisoned") = Some(SegmentCache::new(config));
+	}
+
+	/// Clears the hash-prefix response cache, if it's enabled. Does nothing
+	/// otherwise.
+	#[cfg(feature = "private_searches")]
+	pub fn clear_cache(&self) {
+		if let Some(cache) = self.cache.lock().expect("cache mutex poisoned").as_mut() {
+			cache.clear();
+		}
+	}
+
+	/// Enables validating each fetched segment's end time against the
+	/// video's actual, current duration (scraped from YouTube), so that
+	/// stale submissions against a since-trimmed or re-uploaded video can be
+	/// flagged or dropped. Disabled by default.
+	///
+	/// `tolerance_secs` is how far a segment's end time may exceed the live
+	/// duration before it's considered a mismatch.
+	#[cfg(feature = "youtube_duration_validation")]
+	pub fn set_duration_validation(
+		&mut self,
+		mode: DurationValidationMode,
+		tolerance_secs: f32,
+	) -> &mut Self {
+		self.duration_validation = Some((mode, tolerance_secs));
+		self
+	}
+}
+
+/// Builds the underlying `reqwest::Client`, selecting the TLS backend
+/// according to whichever of this crate's `default-tls`,
+/// `rustls-tls-webpki-roots`, or `rustls-tls-native-roots` features are
+/// enabled (see the crate's `Cargo.toml` for how those map onto reqwest's
+/// own TLS features). If none of them are enabled, reqwest is built
+/// without any TLS backend, so `https://` URLs cannot be reached.
+fn build_http_client() -> reqwest::Client {
+	#[allow(unused_mut)]
+	let mut builder = reqwest::Client::builder();
+
+	#[cfg(any(feature = "rustls-tls-webpki-roots", feature = "rustls-tls-native-roots"))]
+	{
+		builder = builder.use_rustls_tls();
+	}
+
+	builder
+		.build()
+		.expect("the reqwest client builder was not misconfigured")
+}
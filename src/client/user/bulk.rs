@@ -0,0 +1,34 @@
+// Uses
+use futures::stream::{self, StreamExt};
+
+use crate::{error::SponsorBlockResult, segment::{AcceptedCategories, Segment}, Client};
+
+// Function Implementation
+impl Client {
+	/// Fetches segments for many video IDs concurrently, up to the
+	/// concurrency limit set with [`Client::set_bulk_concurrency`].
+	///
+	/// Each video is fetched and errors in isolation: one video returning
+	/// `HttpClient(404)` doesn't prevent the others' results from coming
+	/// back. Results may arrive in a different order than `video_ids`, so
+	/// each is paired with the `video_id` it was fetched for.
+	pub async fn fetch_segments_bulk<S: AsRef<str> + Sync>(
+		&self,
+		video_ids: &[S],
+		accepted_categories: AcceptedCategories,
+	) -> Vec<(String, SponsorBlockResult<Vec<Segment>>)> {
+		stream::iter(video_ids.iter())
+			.map(|video_id| {
+				let video_id = video_id.as_ref();
+				async move {
+					(
+						video_id.to_owned(),
+						self.fetch_segments(video_id, accepted_categories).await,
+					)
+				}
+			})
+			.buffer_unordered(self.bulk_concurrency.max(1))
+			.collect()
+			.await
+	}
+}
@@ -0,0 +1,130 @@
+// Uses
+use serde::Deserialize;
+use serde_json::from_str as from_json_str;
+#[cfg(feature = "private_searches")]
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "private_searches")]
+use crate::util::bytes_to_hex_string;
+use crate::{
+	error::SponsorBlockResult,
+	user::UserInfo,
+	util::get_response_text,
+	Client,
+};
+
+// Function Implementation
+impl Client {
+	/// Fetches a user's public statistics: how many segments they've
+	/// submitted and viewed, their reputation, and whether they're a VIP.
+	///
+	/// # Errors
+	/// Can return any error type from [`SponsorBlockError`]. See the error
+	/// type definitions for explanations of when they might be encountered.
+	///
+	/// [`SponsorBlockError`]: crate::SponsorBlockError
+	pub async fn fetch_user_info(&self, user_id: &str) -> SponsorBlockResult<UserInfo> {
+		// Function Constants
+		const API_ENDPOINT: &str = "/api/userInfo";
+
+		// Function-Specific Deserialization Structs
+		#[derive(Deserialize, Debug, Default)]
+		#[serde(default, rename_all = "camelCase")]
+		struct RawUserInfo {
+			segment_count: i32,
+			view_count: i32,
+			reputation: f32,
+			vip: bool,
+		}
+
+		// Build the request and send it
+		let base_url = &self.base_urls[0];
+		let request;
+		#[cfg(not(feature = "private_searches"))]
+		{
+			request = self
+				.http
+				.get(format!("{base_url}{API_ENDPOINT}"))
+				.query(&[("userID", user_id)]);
+		}
+		#[cfg(feature = "private_searches")]
+		{
+			// Unlike `/skipSegments/<hashPrefix>`, this endpoint always
+			// resolves to exactly one known user, so there's no k-anonymity
+			// set to hide within: send the full hash as the query parameter
+			// rather than truncating it into a hash-prefix path segment.
+			let user_id_hash = {
+				let mut hasher = Sha256::new();
+				Digest::update(&mut hasher, user_id.as_bytes());
+				bytes_to_hex_string(&hasher.finalize()[..])
+			};
+			request = self
+				.http
+				.get(format!("{base_url}{API_ENDPOINT}"))
+				.query(&[("userID", user_id_hash)]);
+		}
+		let response = get_response_text(request.send().await?).await?;
+
+		// Deserialize the response and parse it into the output
+		let raw_user_info = from_json_str::<RawUserInfo>(response.as_str())?;
+		Ok(UserInfo {
+			segments_submitted: raw_user_info.segment_count,
+			segments_viewed: raw_user_info.view_count,
+			reputation: raw_user_info.reputation,
+			vip: raw_user_info.vip,
+		})
+	}
+
+	/// Checks whether a user is a VIP.
+	///
+	/// This is a thin convenience wrapper around `/api/isUserVip` for callers
+	/// who only care about VIP status; use [`fetch_user_info`] if you also
+	/// need the user's other statistics.
+	///
+	/// # Errors
+	/// See the Errors section of [`fetch_user_info`].
+	///
+	/// [`fetch_user_info`]: Self::fetch_user_info
+	pub async fn is_user_vip(&self, user_id: &str) -> SponsorBlockResult<bool> {
+		// Function Constants
+		const API_ENDPOINT: &str = "/api/isUserVip";
+
+		// Function-Specific Deserialization Structs
+		#[derive(Deserialize, Debug, Default)]
+		#[serde(default)]
+		struct RawVipStatus {
+			vip: bool,
+		}
+
+		// Build the request and send it
+		let base_url = &self.base_urls[0];
+		let request;
+		#[cfg(not(feature = "private_searches"))]
+		{
+			request = self
+				.http
+				.get(format!("{base_url}{API_ENDPOINT}"))
+				.query(&[("userID", user_id)]);
+		}
+		#[cfg(feature = "private_searches")]
+		{
+			// See the comment in `fetch_user_info`: this endpoint also
+			// always resolves to exactly one known user, so the full hash
+			// is sent as the query parameter rather than truncated into a
+			// hash-prefix path segment.
+			let user_id_hash = {
+				let mut hasher = Sha256::new();
+				Digest::update(&mut hasher, user_id.as_bytes());
+				bytes_to_hex_string(&hasher.finalize()[..])
+			};
+			request = self
+				.http
+				.get(format!("{base_url}{API_ENDPOINT}"))
+				.query(&[("userID", user_id_hash)]);
+		}
+		let response = get_response_text(request.send().await?).await?;
+
+		// Deserialize the response and parse it into the output
+		Ok(from_json_str::<RawVipStatus>(response.as_str())?.vip)
+	}
+}
@@ -0,0 +1,4 @@
+// Uses
+mod bulk;
+mod info;
+mod segments;
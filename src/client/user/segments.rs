@@ -5,7 +5,9 @@ use serde_json::from_str as from_json_str;
 use sha2::{Digest, Sha256};
 
 #[cfg(feature = "private_searches")]
-use crate::util::bytes_to_hex_string;
+use crate::{client::cache::CacheKey, util::bytes_to_hex_string};
+#[cfg(feature = "youtube_duration_validation")]
+use crate::DurationValidationMode;
 use crate::{
 	api::{api_convert_action_type, api_convert_segment_kind},
 	error::{SponsorBlockError, SponsorBlockResult},
@@ -50,11 +52,20 @@ impl Client {
 	/// retrieved, even if they don't meet the minimum vote threshold. If this
 	/// isn't something you need, use the regular [`fetch_segments`] instead.
 	///
+	/// If the client was constructed with more than one base URL (see
+	/// [`Client::add_mirror`]), each is tried in order: a connection error or
+	/// an empty/404 result moves on to the next one, and the segments from
+	/// the first mirror to succeed are returned. [`HttpClient(404)`] and
+	/// [`NoMatchingVideoHash`] are only returned once every mirror in the
+	/// chain has failed.
+	///
 	/// # Errors
 	/// See the Errors section of the [base version of this
 	/// function](Self::fetch_segments).
 	///
 	/// [`fetch_segments`]: Self::fetch_segments
+	/// [`HttpClient(404)`]: crate::SponsorBlockError::HttpClient
+	/// [`NoMatchingVideoHash`]: crate::SponsorBlockError::NoMatchingVideoHash
 	pub async fn fetch_segments_with_required<S: AsRef<str>>(
 		&self,
 		video_id: &str,
@@ -90,66 +101,139 @@ impl Client {
 			video_duration_upon_submission: f32,
 		}
 
-		// Build the request and send it
-		let mut request;
-		#[cfg(not(feature = "private_searches"))]
-		{
-			request = self
-				.http
-				.get(format!("{}{}", &self.base_url, API_ENDPOINT))
-				.query(&[("videoID", video_id)]);
-		}
-		#[cfg(feature = "private_searches")]
-		{
-			let video_id_hash = {
-				let mut hasher = Sha256::new();
-				Digest::update(&mut hasher, video_id.as_bytes());
-				bytes_to_hex_string(&hasher.finalize()[..])
-			};
-			request = self.http.get(format!(
-				"{}{}",
-				&self.base_url,
-				format!(
-					"{}/{}",
-					API_ENDPOINT,
-					&video_id_hash[0..self.hash_prefix_length as usize]
-				)
-			));
-		}
+		// Queries a single mirror and returns its raw segments, so the outer
+		// loop can try the next mirror in the chain on failure.
+		async fn fetch_from_mirror(
+			client: &Client,
+			base_url: &str,
+			video_id: &str,
+			accepted_categories: AcceptedCategories,
+			required_segments_query: Option<&str>,
+		) -> SponsorBlockResult<Vec<RawSegment>> {
+			#[cfg(not(feature = "private_searches"))]
+			{
+				// Build the request and send it
+				let request = client
+					.http
+					.get(format!("{base_url}{API_ENDPOINT}"))
+					.query(&[("videoID", video_id)])
+					.query(&[("categories", accepted_categories.gen_url_value())])
+					.query(&[("service", &client.service)]);
+				let request = match required_segments_query {
+					Some(required_segments_query) => {
+						request.query(&[("requiredSegments", required_segments_query)])
+					}
+					None => request,
+				};
+				let response = get_response_text(request.send().await?).await?;
 
-		request = request
-			.query(&[("categories", accepted_categories.gen_url_value())])
-			.query(&[("service", &self.service)]);
-		if !required_segments.is_empty() {
-			request = request.query(&[("requiredSegments", to_url_array(required_segments))]);
-		}
-		dbg!(&request);
-		let response = get_response_text(request.send().await?).await?;
-		dbg!(&response);
+				Ok(from_json_str::<Vec<RawSegment>>(response.as_str())?)
+			}
+			#[cfg(feature = "private_searches")]
+			{
+				let video_id_hash = {
+					let mut hasher = Sha256::new();
+					Digest::update(&mut hasher, video_id.as_bytes());
+					bytes_to_hex_string(&hasher.finalize()[..])
+				};
+				let hash_prefix = video_id_hash[0..client.hash_prefix_length as usize].to_owned();
 
-		// Deserialize the response and parse it into the output
-		let mut video_segments;
-		#[cfg(not(feature = "private_searches"))]
-		{
-			video_segments = from_json_str::<Vec<RawSegment>>(response.as_str())?
+				// Only the generic "no required segments" shape is worth
+				// caching: a response shaped by `requiredSegments` is
+				// specific to the video that requested it, not the prefix
+				// as a whole.
+				let cache_key = required_segments_query.is_none().then(|| CacheKey {
+					base_url: base_url.to_owned(),
+					hash_prefix: hash_prefix.clone(),
+					categories: accepted_categories,
+				});
+				let cached_response = cache_key.as_ref().and_then(|cache_key| {
+					client
+						.cache
+						.lock()
+						.expect("cache mutex poisoned")
+						.as_ref()?
+						.get(cache_key)
+						.map(str::to_owned)
+				});
+
+				let response = match cached_response {
+					Some(cached_response) => cached_response,
+					None => {
+						let request = client
+							.http
+							.get(format!("{base_url}{API_ENDPOINT}/{hash_prefix}"))
+							.query(&[("categories", accepted_categories.gen_url_value())])
+							.query(&[("service", &client.service)]);
+						let request = match required_segments_query {
+							Some(required_segments_query) => {
+								request.query(&[("requiredSegments", required_segments_query)])
+							}
+							None => request,
+						};
+						let response = get_response_text(request.send().await?).await?;
+
+						if let Some(cache_key) = cache_key {
+							if let Some(cache) =
+								client.cache.lock().expect("cache mutex poisoned").as_mut()
+							{
+								cache.insert(cache_key, response.clone());
+							}
+						}
+
+						response
+					}
+				};
+
+				for hash_match in from_json_str::<Vec<RawHashMatch>>(response.as_str())?.drain(..) {
+					if hash_match.video_id == video_id {
+						return Ok(hash_match.segments);
+					}
+				}
+				Err(SponsorBlockError::NoMatchingVideoHash)
+			}
 		}
-		#[cfg(feature = "private_searches")]
-		{
-			let mut found_match = false;
-			video_segments = Vec::new();
-			for hash_match in from_json_str::<Vec<RawHashMatch>>(response.as_str())?.drain(..) {
-				if hash_match.video_id == video_id {
-					video_segments = hash_match.segments;
-					found_match = true;
+
+		let required_segments_query =
+			(!required_segments.is_empty()).then(|| to_url_array(required_segments));
+
+		// Try each mirror in the chain in turn, only surfacing an error once
+		// every mirror has been exhausted.
+		let mut video_segments = Vec::new();
+		let mut last_error = None;
+		for (mirror_index, base_url) in self.base_urls.iter().enumerate() {
+			let is_last_mirror = mirror_index == self.base_urls.len() - 1;
+
+			match fetch_from_mirror(
+				self,
+				base_url,
+				video_id,
+				accepted_categories,
+				required_segments_query.as_deref(),
+			)
+			.await
+			{
+				Ok(segments) if segments.is_empty() && !is_last_mirror => continue,
+				Ok(segments) => {
+					video_segments = segments;
 					break;
 				}
-			}
-			if !found_match {
-				return Err(SponsorBlockError::NoMatchingVideoHash);
+				Err(err) if is_last_mirror => {
+					last_error = Some(err);
+				}
+				Err(SponsorBlockError::Reqwest(_) | SponsorBlockError::HttpClient(404)) => {
+					continue;
+				}
+				#[cfg(feature = "private_searches")]
+				Err(SponsorBlockError::NoMatchingVideoHash) => continue,
+				Err(err) => return Err(err),
 			}
 		}
+		if let Some(err) = last_error {
+			return Err(err);
+		}
 
-		video_segments
+		let segments = video_segments
 			.drain(..)
 			.map(|s| {
 				if s.segment[0] > s.segment[1] {
@@ -224,8 +308,51 @@ impl Client {
 					locked: s.locked != 0,
 					votes: s.votes,
 					video_duration_upon_submission: s.video_duration_upon_submission,
+					#[cfg(feature = "youtube_duration_validation")]
+					duration_mismatch: None,
 				})
 			})
-			.collect()
+			.collect::<SponsorBlockResult<Vec<Segment>>>()?;
+
+		#[cfg(not(feature = "youtube_duration_validation"))]
+		{
+			Ok(segments)
+		}
+		#[cfg(feature = "youtube_duration_validation")]
+		{
+			let Some((mode, tolerance_secs)) = self.duration_validation else {
+				return Ok(segments);
+			};
+			let live_duration =
+				crate::client::duration::fetch_live_duration(&self.http, video_id).await?;
+			Ok(apply_duration_validation(
+				segments,
+				live_duration,
+				mode,
+				tolerance_secs,
+			))
+		}
+	}
+}
+
+/// Sets `duration_mismatch` on every segment whose end time exceeds
+/// `live_duration` by more than `tolerance_secs`, and, in [`Drop`] mode,
+/// removes those segments entirely.
+///
+/// [`Drop`]: DurationValidationMode::Drop
+#[cfg(feature = "youtube_duration_validation")]
+fn apply_duration_validation(
+	mut segments: Vec<Segment>,
+	live_duration: f32,
+	mode: DurationValidationMode,
+	tolerance_secs: f32,
+) -> Vec<Segment> {
+	for segment in &mut segments {
+		let mismatch = segment.segment.end_time() - live_duration;
+		segment.duration_mismatch = (mismatch > tolerance_secs).then_some(mismatch);
+	}
+	if mode == DurationValidationMode::Drop {
+		segments.retain(|segment| segment.duration_mismatch.is_none());
 	}
+	segments
 }
@@ -0,0 +1,57 @@
+// Uses
+use std::fmt;
+
+// Type Definitions
+/// The error type returned by fallible operations throughout this crate.
+#[derive(Debug)]
+pub enum SponsorBlockError {
+	/// The underlying HTTP client failed to complete the request.
+	Reqwest(reqwest::Error),
+	/// The response body could not be deserialized as the expected JSON shape.
+	Json(serde_json::Error),
+	/// The server responded with a non-success HTTP status code.
+	HttpClient(u16),
+	/// A value returned by the server did not match any of this crate's
+	/// known enum variants.
+	UnknownVariant(String),
+	/// Data failed a sanity check: a segment's timestamps (e.g. a negative
+	/// or out-of-order timestamp), or a YouTube watch page without a
+	/// parseable duration.
+	BadData(String),
+	/// None of the hash matches returned for a hash-prefix query contained
+	/// the requested video ID.
+	NoMatchingVideoHash,
+}
+
+/// A convenience alias for `Result<T, SponsorBlockError>`.
+pub type SponsorBlockResult<T> = Result<T, SponsorBlockError>;
+
+// Trait Implementations
+impl fmt::Display for SponsorBlockError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Reqwest(err) => write!(f, "HTTP request failed: {err}"),
+			Self::Json(err) => write!(f, "failed to deserialize response: {err}"),
+			Self::HttpClient(status) => write!(f, "server responded with status {status}"),
+			Self::UnknownVariant(value) => write!(f, "unrecognized value from server: {value}"),
+			Self::BadData(reason) => write!(f, "data failed validation: {reason}"),
+			Self::NoMatchingVideoHash => {
+				write!(f, "no hash match for the requested video ID was found")
+			}
+		}
+	}
+}
+
+impl std::error::Error for SponsorBlockError {}
+
+impl From<reqwest::Error> for SponsorBlockError {
+	fn from(err: reqwest::Error) -> Self {
+		Self::Reqwest(err)
+	}
+}
+
+impl From<serde_json::Error> for SponsorBlockError {
+	fn from(err: serde_json::Error) -> Self {
+		Self::Json(err)
+	}
+}
@@ -0,0 +1,29 @@
+//! A client for the [SponsorBlock](https://sponsor.ajay.app/) API, used to
+//! fetch and act on crowdsourced segments marking sponsor spots,
+//! self-promotion, and other skippable content in videos.
+//!
+//! # TLS backend features
+//! Like reqwest itself, this crate lets you pick a TLS backend via Cargo
+//! features instead of forcing one on you:
+//! - `default-tls` (on by default): reqwest's native-tls backend.
+//! - `rustls-tls-webpki-roots`: rustls with Mozilla's bundled webpki roots,
+//!   useful for static musl binaries that don't want to link OpenSSL.
+//! - `rustls-tls-native-roots`: rustls using the OS's native root store.
+//!
+//! Enable at most one of the `rustls-tls-*` features alongside
+//! `default-features = false` to avoid linking a TLS backend you're not
+//! using.
+
+pub mod api;
+pub mod error;
+pub mod segment;
+pub mod user;
+pub mod util;
+
+mod client;
+
+#[cfg(feature = "private_searches")]
+pub use client::CacheConfig;
+pub use client::Client;
+#[cfg(feature = "youtube_duration_validation")]
+pub use client::DurationValidationMode;
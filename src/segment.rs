@@ -0,0 +1,201 @@
+// Type Definitions
+/// The set of segment categories a caller is willing to receive.
+///
+/// Construct one with the associated constants or [`AcceptedCategories::all`],
+/// then combine them with `|` as needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AcceptedCategories(u16);
+
+impl AcceptedCategories {
+	pub const SPONSOR: Self = Self(1 << 0);
+	pub const UNPAID_SELF_PROMOTION: Self = Self(1 << 1);
+	pub const INTERACTION_REMINDER: Self = Self(1 << 2);
+	pub const HIGHLIGHT: Self = Self(1 << 3);
+	pub const INTERMISSION_INTRO_ANIMATION: Self = Self(1 << 4);
+	pub const ENDCARDS_CREDITS: Self = Self(1 << 5);
+	pub const PREVIEW_RECAP: Self = Self(1 << 6);
+	pub const NON_MUSIC: Self = Self(1 << 7);
+
+	/// All known categories.
+	#[must_use]
+	pub const fn all() -> Self {
+		Self(
+			Self::SPONSOR.0
+				| Self::UNPAID_SELF_PROMOTION.0
+				| Self::INTERACTION_REMINDER.0
+				| Self::HIGHLIGHT.0
+				| Self::INTERMISSION_INTRO_ANIMATION.0
+				| Self::ENDCARDS_CREDITS.0
+				| Self::PREVIEW_RECAP.0
+				| Self::NON_MUSIC.0,
+		)
+	}
+
+	#[must_use]
+	pub const fn contains(self, kind: ActionableSegmentKind) -> bool {
+		self.0 & Self::for_kind(kind).0 != 0
+	}
+
+	const fn for_kind(kind: ActionableSegmentKind) -> Self {
+		match kind {
+			ActionableSegmentKind::Sponsor => Self::SPONSOR,
+			ActionableSegmentKind::UnpaidSelfPromotion => Self::UNPAID_SELF_PROMOTION,
+			ActionableSegmentKind::InteractionReminder => Self::INTERACTION_REMINDER,
+			ActionableSegmentKind::Highlight => Self::HIGHLIGHT,
+			ActionableSegmentKind::IntermissionIntroAnimation => {
+				Self::INTERMISSION_INTRO_ANIMATION
+			}
+			ActionableSegmentKind::EndcardsCredits => Self::ENDCARDS_CREDITS,
+			ActionableSegmentKind::PreviewRecap => Self::PREVIEW_RECAP,
+			ActionableSegmentKind::NonMusic => Self::NON_MUSIC,
+		}
+	}
+
+	/// Renders the categories as the JSON array string the API expects for
+	/// its `categories` query parameter.
+	#[must_use]
+	pub fn gen_url_value(self) -> String {
+		let mut categories = Vec::new();
+		for kind in ActionableSegmentKind::ALL {
+			if self.contains(kind) {
+				categories.push(format!("\"{}\"", kind.as_api_str()));
+			}
+		}
+		format!("[{}]", categories.join(","))
+	}
+
+	/// The raw bitset backing this value, for code that needs to store or
+	/// key on it (e.g. a cache) without depending on its internal layout.
+	#[cfg(feature = "private_searches")]
+	#[must_use]
+	pub(crate) const fn to_bits(self) -> u16 {
+		self.0
+	}
+
+	/// Reconstructs a value from bits previously obtained from
+	/// [`AcceptedCategories::to_bits`].
+	#[cfg(feature = "private_searches")]
+	#[must_use]
+	pub(crate) const fn from_bits(bits: u16) -> Self {
+		Self(bits)
+	}
+}
+
+impl std::ops::BitOr for AcceptedCategories {
+	type Output = Self;
+
+	fn bitor(self, rhs: Self) -> Self {
+		Self(self.0 | rhs.0)
+	}
+}
+
+/// The category a segment was submitted under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionableSegmentKind {
+	Sponsor,
+	UnpaidSelfPromotion,
+	InteractionReminder,
+	Highlight,
+	IntermissionIntroAnimation,
+	EndcardsCredits,
+	PreviewRecap,
+	NonMusic,
+}
+
+impl ActionableSegmentKind {
+	pub(crate) const ALL: [Self; 8] = [
+		Self::Sponsor,
+		Self::UnpaidSelfPromotion,
+		Self::InteractionReminder,
+		Self::Highlight,
+		Self::IntermissionIntroAnimation,
+		Self::EndcardsCredits,
+		Self::PreviewRecap,
+		Self::NonMusic,
+	];
+
+	pub(crate) const fn as_api_str(self) -> &'static str {
+		match self {
+			Self::Sponsor => "sponsor",
+			Self::UnpaidSelfPromotion => "selfpromo",
+			Self::InteractionReminder => "interaction",
+			Self::Highlight => "poi_highlight",
+			Self::IntermissionIntroAnimation => "intro",
+			Self::EndcardsCredits => "outro",
+			Self::PreviewRecap => "preview",
+			Self::NonMusic => "music_offtopic",
+		}
+	}
+}
+
+/// How a client is expected to act on a segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionType {
+	Skip,
+	Mute,
+	Full,
+	Poi,
+}
+
+/// A section of a video with a start and end time, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeSection {
+	pub start: f32,
+	pub end: f32,
+}
+
+/// A single point in time in a video, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimePoint {
+	pub point: f32,
+}
+
+/// A segment, tagged with its category and carrying either a time range or a
+/// single point, depending on the category.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActionableSegment {
+	Sponsor(TimeSection),
+	UnpaidSelfPromotion(TimeSection),
+	InteractionReminder(TimeSection),
+	Highlight(TimePoint),
+	IntermissionIntroAnimation(TimeSection),
+	EndcardsCredits(TimeSection),
+	PreviewRecap(TimeSection),
+	NonMusic(TimeSection),
+}
+
+impl ActionableSegment {
+	/// The segment's end time, in seconds. For point-in-time segments like
+	/// highlights, this is the point itself.
+	#[must_use]
+	pub const fn end_time(self) -> f32 {
+		match self {
+			Self::Highlight(TimePoint { point }) => point,
+			Self::Sponsor(section)
+			| Self::UnpaidSelfPromotion(section)
+			| Self::InteractionReminder(section)
+			| Self::IntermissionIntroAnimation(section)
+			| Self::EndcardsCredits(section)
+			| Self::PreviewRecap(section)
+			| Self::NonMusic(section) => section.end,
+		}
+	}
+}
+
+/// A fully parsed segment returned by the SponsorBlock API.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+	pub segment: ActionableSegment,
+	pub action_type: ActionType,
+	pub uuid: String,
+	pub locked: bool,
+	pub votes: i32,
+	pub video_duration_upon_submission: f32,
+	/// How far `segment`'s end time exceeds the video's actual, current
+	/// duration, in seconds, if [duration
+	/// validation](crate::Client::set_duration_validation) was enabled and
+	/// found a discrepancy worth surfacing. `None` if validation wasn't
+	/// enabled or the segment matched within tolerance.
+	#[cfg(feature = "youtube_duration_validation")]
+	pub duration_mismatch: Option<f32>,
+}
@@ -0,0 +1,13 @@
+// Type Definitions
+/// A SponsorBlock user's public statistics, as returned by `/api/userInfo`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UserInfo {
+	/// The number of segments this user has submitted.
+	pub segments_submitted: i32,
+	/// The number of segments this user has skipped by viewing them.
+	pub segments_viewed: i32,
+	/// The user's reputation score.
+	pub reputation: f32,
+	/// Whether the user is a VIP.
+	pub vip: bool,
+}
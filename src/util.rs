@@ -0,0 +1,43 @@
+// Uses
+use reqwest::Response;
+
+use crate::error::{SponsorBlockError, SponsorBlockResult};
+
+// Function Implementation
+/// Reads a response's body as text, first checking that the status code
+/// indicates success.
+///
+/// # Errors
+/// Returns [`SponsorBlockError::HttpClient`] if the status code isn't a
+/// success code, or [`SponsorBlockError::Reqwest`] if the body can't be
+/// read.
+pub async fn get_response_text(response: Response) -> SponsorBlockResult<String> {
+	let status = response.status();
+	if !status.is_success() {
+		return Err(SponsorBlockError::HttpClient(status.as_u16()));
+	}
+	Ok(response.text().await?)
+}
+
+/// Renders a slice of string-like values as the bracketed, comma-separated
+/// list of quoted strings (e.g. `["a","b"]`) the API expects for parameters
+/// like `requiredSegments`. No URL encoding is applied here.
+#[must_use]
+pub fn to_url_array<S: AsRef<str>>(items: &[S]) -> String {
+	format!(
+		"[{}]",
+		items
+			.iter()
+			.map(|item| format!("\"{}\"", item.as_ref()))
+			.collect::<Vec<_>>()
+			.join(",")
+	)
+}
+
+/// Hex-encodes a byte slice, e.g. for rendering a SHA-256 digest as the
+/// lowercase hex string the API expects.
+#[cfg(feature = "private_searches")]
+#[must_use]
+pub fn bytes_to_hex_string(bytes: &[u8]) -> String {
+	bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}